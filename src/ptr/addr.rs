@@ -0,0 +1,306 @@
+use crate::{
+	mutability::{
+		Const,
+		Mut,
+		Mutability,
+	},
+	store::BitStore,
+};
+
+use core::{
+	convert::TryFrom,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	marker::PhantomData,
+	ptr::NonNull,
+};
+
+/** The address of a memory element, with a marker for its mutability
+permission.
+
+# Original
+
+[`*const T` and `*mut T`](https://doc.rust-lang.org/std/primitive.pointer.html)
+
+# API Differences
+
+This wraps a [`NonNull<T>`], rather than being one of the two raw-pointer
+types, so that [`BitPtr`] can carry mutability as a type parameter instead
+of needing two near-equivalent sibling types of its own.
+
+Every constructor that accepts a real memory address (a reference, a raw
+pointer, or a [`NonNull`]) preserves that address's provenance: the
+pointer arithmetic in [`Self::offset`] and [`Self::wrapping_offset`] is
+implemented with the underlying pointer's own `offset`/`wrapping_offset`,
+never with a `usize as *mut T` cast. [`Self::invalid`] is the deliberate
+exception, mirroring [`core::ptr::invalid_mut`]: it produces an address
+that is meaningful for comparison but carries no provenance and must
+never be dereferenced.
+
+[`BitPtr`]: crate::ptr::BitPtr
+[`NonNull`]: core::ptr::NonNull
+[`NonNull<T>`]: core::ptr::NonNull
+**/
+pub struct Address<T, M = Const>
+where
+	T: BitStore,
+	M: Mutability,
+{
+	/// The real memory address of the referent element.
+	inner: NonNull<T>,
+	/// Marks whether this address may be used to produce a `&mut T`.
+	_mut: PhantomData<M>,
+}
+
+impl<T, M> Address<T, M>
+where
+	T: BitStore,
+	M: Mutability,
+{
+	/// A well-aligned, non-null address that is never read from or
+	/// written through. Used to seed [`BitPtr::DANGLING`].
+	///
+	/// [`BitPtr::DANGLING`]: crate::ptr::BitPtr::DANGLING
+	pub(crate) const DANGLING: Self = Self {
+		inner: NonNull::dangling(),
+		_mut: PhantomData,
+	};
+
+	/// Produces the address of the referent element as a bare integer.
+	///
+	/// # Original
+	///
+	/// [`pointer::addr`](https://doc.rust-lang.org/std/primitive.pointer.html#method.addr)
+	///
+	/// # API Differences
+	///
+	/// As with the standard-library method it mirrors, this discards
+	/// provenance: the result may be compared, hashed, or logged, but must
+	/// not be cast back into a pointer and dereferenced.
+	pub fn addr(&self) -> usize {
+		self.inner.as_ptr().addr()
+	}
+
+	/// Constructs an address with a meaningful integer value, but no
+	/// provenance.
+	///
+	/// # Original
+	///
+	/// [`core::ptr::invalid_mut`](https://doc.rust-lang.org/core/ptr/fn.invalid_mut.html)
+	///
+	/// # API Differences
+	///
+	/// The produced address reports `addr` from [`Self::addr`], but is not
+	/// derived from any live allocation. It must never be dereferenced; it
+	/// exists only as a placeholder, to be compared against other
+	/// addresses or rebased onto a live allocation before use.
+	///
+	/// # Panics
+	///
+	/// This panics if `addr` is zero, since [`Address`] is never null.
+	pub fn invalid(addr: usize) -> Self {
+		assert_ne!(addr, 0, "an `Address` may never be null");
+		Self {
+			inner: unsafe {
+				NonNull::new_unchecked(core::ptr::invalid_mut::<T>(addr))
+			},
+			_mut: PhantomData,
+		}
+	}
+
+	/// Offsets the address by `count` elements of `T`.
+	///
+	/// # Safety
+	///
+	/// This has the same safety requirements as [`<*const T>::offset`]: the
+	/// resulting address, and every intermediate address between `self`
+	/// and it, must not overflow `isize` or leave the bounds of the
+	/// allocated object that `self` points into.
+	///
+	/// [`<*const T>::offset`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset
+	pub(crate) unsafe fn offset(self, count: isize) -> Self {
+		Self {
+			inner: NonNull::new_unchecked(self.inner.as_ptr().offset(count)),
+			_mut: PhantomData,
+		}
+	}
+
+	/// Offsets the address by `count` elements of `T`, permitting the
+	/// result to wrap around the address space instead of requiring the
+	/// caller to prove it will not.
+	///
+	/// # Panics
+	///
+	/// This panics if the wrapped address is null, since [`Address`] is
+	/// never null.
+	pub(crate) fn wrapping_offset(self, count: isize) -> Self {
+		Self {
+			inner: NonNull::new(self.inner.as_ptr().wrapping_offset(count))
+				.expect("an `Address` may never be null"),
+			_mut: PhantomData,
+		}
+	}
+
+	/// Computes the distance, in elements of `T`, between two addresses.
+	///
+	/// # Safety
+	///
+	/// `self` and `origin` must point into the same allocated object, per
+	/// the safety rules of [`<*const T>::offset_from`].
+	///
+	/// [`<*const T>::offset_from`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset_from
+	pub(crate) unsafe fn offset_from(self, origin: Self) -> isize {
+		self.inner.as_ptr().offset_from(origin.inner.as_ptr())
+	}
+
+	/// Views the address as a `*const T`, regardless of `M`.
+	pub(crate) fn to_const(&self) -> *const T {
+		self.inner.as_ptr() as *const T
+	}
+
+	/// Views the address as a `*const T::Access`, for element-wise atomic
+	/// or cell-guarded writes.
+	pub(crate) fn to_access(&self) -> *const <T as BitStore>::Access {
+		self.inner.as_ptr() as *const T as *const <T as BitStore>::Access
+	}
+}
+
+impl<T> Address<T, Mut>
+where T: BitStore
+{
+	/// Views the address as a `*mut T`.
+	pub(crate) fn to_mut(&self) -> *mut T {
+		self.inner.as_ptr()
+	}
+}
+
+impl<T, M> Clone for Address<T, M>
+where
+	T: BitStore,
+	M: Mutability,
+{
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, M> Copy for Address<T, M>
+where
+	T: BitStore,
+	M: Mutability,
+{
+}
+
+impl<T, M> Debug for Address<T, M>
+where
+	T: BitStore,
+	M: Mutability,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "Address({:p})", self.inner.as_ptr())
+	}
+}
+
+impl<T> From<&T> for Address<T, Const>
+where T: BitStore
+{
+	fn from(src: &T) -> Self {
+		Self {
+			inner: NonNull::from(src),
+			_mut: PhantomData,
+		}
+	}
+}
+
+impl<T> From<&mut T> for Address<T, Mut>
+where T: BitStore
+{
+	fn from(src: &mut T) -> Self {
+		Self {
+			inner: NonNull::from(src),
+			_mut: PhantomData,
+		}
+	}
+}
+
+impl<T> From<NonNull<T>> for Address<T, Mut>
+where T: BitStore
+{
+	fn from(src: NonNull<T>) -> Self {
+		Self {
+			inner: src,
+			_mut: PhantomData,
+		}
+	}
+}
+
+impl<T> TryFrom<*const T> for Address<T, Const>
+where T: BitStore
+{
+	type Error = AddressError<T>;
+
+	fn try_from(src: *const T) -> Result<Self, Self::Error> {
+		if src.align_offset(core::mem::align_of::<T>()) != 0 {
+			return Err(AddressError::Misaligned(src));
+		}
+		NonNull::new(src as *mut T)
+			.map(|inner| Self {
+				inner,
+				_mut: PhantomData,
+			})
+			.ok_or(AddressError::Null)
+	}
+}
+
+impl<T> TryFrom<*mut T> for Address<T, Mut>
+where T: BitStore
+{
+	type Error = AddressError<T>;
+
+	fn try_from(src: *mut T) -> Result<Self, Self::Error> {
+		if src.align_offset(core::mem::align_of::<T>()) != 0 {
+			return Err(AddressError::Misaligned(src as *const T));
+		}
+		NonNull::new(src)
+			.map(|inner| Self {
+				inner,
+				_mut: PhantomData,
+			})
+			.ok_or(AddressError::Null)
+	}
+}
+
+/// An error produced when constructing an [`Address`] from a raw pointer
+/// that cannot validly address a `T`.
+///
+/// [`Address`]: crate::ptr::Address
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressError<T> {
+	/// The source pointer was null.
+	Null,
+	/// The source pointer was not aligned for `T`.
+	Misaligned(*const T),
+}
+
+impl<T> Display for AddressError<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Null => fmt.write_str("a bit-pointer address cannot be null"),
+			Self::Misaligned(ptr) => write!(
+				fmt,
+				"address {:p} is not aligned to {}",
+				ptr,
+				core::mem::align_of::<T>(),
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for AddressError<T> {
+}
@@ -0,0 +1,23 @@
+//! Bit-level pointers.
+//!
+//! This module collects the pointer types that know how to address
+//! individual bits inside a memory element, and the building blocks they
+//! share.
+
+mod addr;
+mod single;
+
+pub use self::{
+	addr::{
+		Address,
+		AddressError,
+	},
+	single::{
+		BitPtr,
+		Live,
+		RelBitPtr,
+		Unknown,
+		Valid,
+		Validity,
+	},
+};
@@ -1,6 +1,7 @@
 use crate::{
 	access::BitAccess,
 	index::BitIdx,
+	mem::BitRegister,
 	mutability::{
 		Const,
 		Mut,
@@ -25,6 +26,46 @@ use core::{
 	ptr::NonNull,
 };
 
+mod seal {
+	/// Forbids downstream implementation of [`super::Validity`].
+	pub trait Sealed {}
+}
+
+/// Type-state marker for what a [`BitPtr`] has proven about its referent.
+///
+/// A freshly constructed `BitPtr` knows nothing about whether its referent
+/// element is allocated or initialized, and is stuck in the [`Unknown`]
+/// state: every access to it must go through an `unsafe fn` that documents
+/// the obligations the caller must discharge by hand. [`BitPtr::assume_live`]
+/// and [`BitPtr::try_live`] lift a pointer into [`Live`] — allocated, and
+/// not in violation of Rust's aliasing rules — and [`assume_valid`] lifts
+/// either state the rest of the way into [`Valid`] — also initialized —
+/// at which point [`BitPtr::read`] and [`BitPtr::write`] become safe to
+/// call. This moves the proof obligation to wherever the pointer was
+/// constructed, rather than to every call site that dereferences it.
+///
+/// [`assume_valid`]: BitPtr::assume_valid
+pub trait Validity: 'static + seal::Sealed {}
+
+/// A [`BitPtr`] with no proof about its referent. This is the state
+/// produced by every safe constructor.
+pub struct Unknown;
+
+/// A [`BitPtr`] proven to address an allocated element with no aliasing
+/// violations. The element may not yet be initialized.
+pub struct Live;
+
+/// A [`BitPtr`] proven to address an allocated, non-aliased, initialized
+/// element. [`BitPtr::read`] and [`BitPtr::write`] are safe in this state.
+pub struct Valid;
+
+impl seal::Sealed for Unknown {}
+impl seal::Sealed for Live {}
+impl seal::Sealed for Valid {}
+impl Validity for Unknown {}
+impl Validity for Live {}
+impl Validity for Valid {}
+
 /** An opaque non-null pointer to a single bit in a memory element.
 
 # Original
@@ -39,12 +80,26 @@ underlying memory element and the ordering of bits within it.
 Additionally, the types corresponding to raw pointers take a third type
 parameter to encode mutability, rather than follow the standard library
 convention of having two near-equivalent sibling types.
+
+`BitPtr` follows the strict-provenance discipline: [`Self::addr`] discards
+provenance and yields a bare integer suitable only for comparison, while
+every constructor that produces a dereferenceable pointer (`new`,
+`new_unchecked`, and the arithmetic in [`Self::offset`] and
+[`Self::wrapping_offset`]) derives its address from a live pointer rather
+than casting an integer back into one. [`Self::invalid`] is the one
+exception: it produces a pointer with a meaningful address and no
+provenance at all, for use as a comparison sentinel.
+
+A fourth type parameter, `V`, carries type-state proving what is known
+about the referent; see [`Validity`] for the states and how to move
+between them.
 **/
-pub struct BitPtr<O, T, M>
+pub struct BitPtr<O, T, M, V = Unknown>
 where
 	O: BitOrder,
 	T: BitStore,
 	M: Mutability,
+	V: Validity,
 {
 	/// Address of the referent element.
 	addr: Address<T, M>,
@@ -52,9 +107,178 @@ where
 	head: BitIdx<T::Mem>,
 	/// The ordering used to map `self.head` to an electrical position.
 	_ord: PhantomData<O>,
+	/// What has been proven about the referent element.
+	_val: PhantomData<V>,
+}
+
+impl<O, T, M, V> BitPtr<O, T, M, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+	V: Validity,
+{
+	/// Decomposes the pointer into its element address and bit index.
+	pub fn raw_parts(self) -> (Address<T, M>, BitIdx<T::Mem>) {
+		(self.addr, self.head)
+	}
+
+	/// Offsets a pointer by some number of bits.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `count`: The number of bits, in either direction, by which to
+	///   offset `self`. Negative values move towards lower addresses, and
+	///   positive values move towards higher addresses.
+	///
+	/// # Returns
+	///
+	/// A pointer to the bit `count` bits away from `self`. The element
+	/// address and in-element index are recomputed with Euclidean division
+	/// against the element's bit width, so this correctly crosses element
+	/// boundaries in either direction. The returned pointer is downgraded
+	/// to the [`Unknown`] validity state: whatever `self` had proven about
+	/// its referent does not necessarily hold at the new position (it may
+	/// be uninitialized, or outside the original allocation), so the type
+	/// system requires that proof be redone with [`BitPtr::assume_live`],
+	/// [`BitPtr::try_live`], or [`BitPtr::assume_valid`] before it can be
+	/// read or written safely again.
+	///
+	/// # Safety
+	///
+	/// This has the same safety requirements as [`<*const T>::offset`]: the
+	/// resulting pointer, and every intermediate bit between `self` and it,
+	/// must not overflow `isize` or leave the bounds of the allocated
+	/// object that `self` points into.
+	///
+	/// [`<*const T>::offset`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset
+	pub unsafe fn offset(self, count: isize) -> BitPtr<O, T, M, Unknown> {
+		let bits = <T::Mem as BitRegister>::BITS as isize;
+		let pos = self.head.value() as isize + count;
+
+		BitPtr {
+			addr: self.addr.offset(pos.div_euclid(bits)),
+			head: BitIdx::new_unchecked(pos.rem_euclid(bits) as u8),
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
+	}
+
+	/// Offsets a pointer by some number of bits, permitting the address to
+	/// wrap around the address space instead of requiring the caller to
+	/// prove it will not.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `count`: The number of bits, in either direction, by which to
+	///   offset `self`.
+	///
+	/// # Returns
+	///
+	/// A pointer to the bit `count` bits away from `self`, computed the
+	/// same way as [`offset`], but safe to call with any `count`. As with
+	/// [`offset`], the result is downgraded to the [`Unknown`] validity
+	/// state, since a safe function cannot take `self`'s proof about its
+	/// referent on faith at an address it did not check.
+	///
+	/// # Panics
+	///
+	/// This panics if the wrapped element address is null.
+	///
+	/// [`offset`]: Self::offset
+	pub fn wrapping_offset(self, count: isize) -> BitPtr<O, T, M, Unknown> {
+		let bits = <T::Mem as BitRegister>::BITS as isize;
+		let pos = self.head.value() as isize + count;
+
+		BitPtr {
+			addr: self.addr.wrapping_offset(pos.div_euclid(bits)),
+			head: unsafe { BitIdx::new_unchecked(pos.rem_euclid(bits) as u8) },
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
+	}
+
+	/// Offsets a pointer by some number of bits, towards higher addresses.
+	///
+	/// # Safety
+	///
+	/// See [`offset`](Self::offset).
+	pub unsafe fn add(self, count: usize) -> BitPtr<O, T, M, Unknown> {
+		self.offset(count as isize)
+	}
+
+	/// Offsets a pointer by some number of bits, towards lower addresses.
+	///
+	/// # Safety
+	///
+	/// See [`offset`](Self::offset).
+	pub unsafe fn sub(self, count: usize) -> BitPtr<O, T, M, Unknown> {
+		self.offset(-(count as isize))
+	}
+
+	/// Computes the distance, in bits, between two pointers.
+	///
+	/// # Parameters
+	///
+	/// - `self`: The pointer to measure from `origin`.
+	/// - `origin`: The pointer being measured against.
+	///
+	/// # Returns
+	///
+	/// The number of bits between `origin` and `self`: negative when
+	/// `self` is before `origin`, positive when `self` is after it, and
+	/// zero when they are equal, matching the convention of
+	/// [`<*const T>::offset_from`].
+	///
+	/// # Safety
+	///
+	/// `self` and `origin` must point into the same allocated object, per
+	/// the safety rules of [`<*const T>::offset_from`].
+	///
+	/// [`<*const T>::offset_from`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset_from
+	pub unsafe fn offset_from(self, origin: Self) -> isize {
+		let bits = <T::Mem as BitRegister>::BITS as isize;
+		self.addr.offset_from(origin.addr) * bits
+			+ (self.head.value() as isize - origin.head.value() as isize)
+	}
+
+	/// Produces the address of the referent element as a bare integer.
+	///
+	/// # Original
+	///
+	/// [`pointer::addr`](https://doc.rust-lang.org/std/primitive.pointer.html#method.addr)
+	///
+	/// # API Differences
+	///
+	/// As with the standard-library method it mirrors, this discards the
+	/// pointer's provenance. The returned integer is suitable for
+	/// comparison, hashing, or logging, but must never be cast back into a
+	/// pointer and dereferenced; synthesize new, valid, pointers through
+	/// [`Self::wrapping_offset`] or another provenance-preserving
+	/// constructor instead.
+	pub fn addr(self) -> usize {
+		self.addr.addr()
+	}
+
+	/// Discards whatever has been proven about the referent, returning the
+	/// pointer to the [`Unknown`] state.
+	///
+	/// This is useful when a pointer is about to be handed to code that
+	/// cannot be trusted to preserve the invariants the current state
+	/// relies on, such as code that mutates the surrounding allocation.
+	pub fn forget_validity(self) -> BitPtr<O, T, M, Unknown> {
+		BitPtr {
+			addr: self.addr,
+			head: self.head,
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
+	}
 }
 
-impl<O, T, M> BitPtr<O, T, M>
+impl<O, T, M> BitPtr<O, T, M, Unknown>
 where
 	O: BitOrder,
 	T: BitStore,
@@ -64,6 +288,7 @@ where
 		addr: Address::DANGLING,
 		head: BitIdx::ZERO,
 		_ord: PhantomData,
+		_val: PhantomData,
 	};
 
 	/// Constructs a new single-bit pointer from an element address and a bit
@@ -79,7 +304,9 @@ where
 	///
 	/// An opaque pointer to a single bit within a memory element. This cannot
 	/// be cast to any raw pointer type. If `addr` is null, or incorrectly
-	/// aligned for `T`, this returns an error rather than a pointer.
+	/// aligned for `T`, this returns an error rather than a pointer. The
+	/// pointer begins in the [`Unknown`] validity state; use
+	/// [`Self::assume_live`] or [`Self::try_live`] to move it forward.
 	///
 	/// [`NonNull`]: core::ptr::NonNull
 	pub fn new<A>(
@@ -117,12 +344,30 @@ where
 			addr,
 			head,
 			_ord: PhantomData,
+			_val: PhantomData,
 		}
 	}
 
-	/// Decomposes the pointer into its element address and bit index.
-	pub fn raw_parts(self) -> (Address<T, M>, BitIdx<T::Mem>) {
-		(self.addr, self.head)
+	/// Constructs a pointer with a meaningful address, but no provenance.
+	///
+	/// # Original
+	///
+	/// [`core::ptr::invalid_mut`](https://doc.rust-lang.org/core/ptr/fn.invalid_mut.html)
+	///
+	/// # API Differences
+	///
+	/// The produced pointer reports `addr` from [`Self::addr`], but is not
+	/// derived from any live allocation and carries no provenance. It must
+	/// never be passed to [`Self::read`] or [`Self::write`]; it exists only
+	/// as a placeholder, to be compared against other pointers or rebased
+	/// onto a live allocation before use.
+	pub fn invalid(addr: usize, head: BitIdx<T::Mem>) -> Self {
+		Self {
+			addr: Address::invalid(addr),
+			head,
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
 	}
 
 	/// Reads the referent bit out of memory.
@@ -136,9 +381,65 @@ where
 	pub unsafe fn read(self) -> bool {
 		(&*self.addr.to_const()).get_bit::<O>(self.head)
 	}
+
+	/// Asserts that the referent element is allocated and not aliased.
+	///
+	/// # Safety
+	///
+	/// The caller must guarantee that the referent element is allocated
+	/// and that no access through `self` will violate Rust's aliasing
+	/// rules, for as long as the returned pointer is used in the [`Live`]
+	/// state.
+	pub unsafe fn assume_live(self) -> BitPtr<O, T, M, Live> {
+		BitPtr {
+			addr: self.addr,
+			head: self.head,
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
+	}
+
+	/// Attempts to prove that the referent element is live.
+	///
+	/// # Returns
+	///
+	/// `None` if `self` carries this type's dangling sentinel address,
+	/// which cannot possibly be live; `Some` otherwise.
+	///
+	/// This check is necessarily weak: it only catches the one sentinel
+	/// address this type itself hands out. A pointer built from
+	/// [`Self::invalid`] with some other, unrelated address is
+	/// indistinguishable from a genuine one by address alone, and will
+	/// still pass. Callers that construct pointers with [`Self::invalid`]
+	/// must not rely on `try_live` to catch them; use
+	/// [`Self::assume_live`] only where the referent is actually known to
+	/// be live.
+	pub fn try_live(self) -> Option<BitPtr<O, T, M, Live>> {
+		if self.addr() == Self::DANGLING.addr() {
+			return None;
+		}
+		Some(unsafe { self.assume_live() })
+	}
+
+	/// Asserts that the referent element is allocated, not aliased, and
+	/// initialized.
+	///
+	/// # Safety
+	///
+	/// The caller must guarantee that the referent element is allocated,
+	/// initialized, and not in aliasing violations, for as long as the
+	/// returned pointer is used in the [`Valid`] state.
+	pub unsafe fn assume_valid(self) -> BitPtr<O, T, M, Valid> {
+		BitPtr {
+			addr: self.addr,
+			head: self.head,
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
+	}
 }
 
-impl<O, T> BitPtr<O, T, Mut>
+impl<O, T> BitPtr<O, T, Mut, Unknown>
 where
 	O: BitOrder,
 	T: BitStore,
@@ -156,11 +457,65 @@ where
 	}
 }
 
-impl<O, T, M> Clone for BitPtr<O, T, M>
+impl<O, T, M> BitPtr<O, T, M, Live>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+{
+	/// Asserts that the referent element is also initialized.
+	///
+	/// # Safety
+	///
+	/// The caller must guarantee that the referent element is initialized,
+	/// for as long as the returned pointer is used in the [`Valid`] state.
+	pub unsafe fn assume_valid(self) -> BitPtr<O, T, M, Valid> {
+		BitPtr {
+			addr: self.addr,
+			head: self.head,
+			_ord: PhantomData,
+			_val: PhantomData,
+		}
+	}
+}
+
+impl<O, T, M> BitPtr<O, T, M, Valid>
 where
 	O: BitOrder,
 	T: BitStore,
 	M: Mutability,
+{
+	/// Reads the referent bit out of memory.
+	///
+	/// The [`Valid`] type-state discharges the obligations that the
+	/// [`Unknown`]-state [`read`](BitPtr::read) documents as `unsafe`, so
+	/// this is safe to call.
+	pub fn read(&self) -> bool {
+		unsafe { (&*self.addr.to_const()).get_bit::<O>(self.head) }
+	}
+}
+
+impl<O, T> BitPtr<O, T, Mut, Valid>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Writes a bit into the referent slot.
+	///
+	/// The [`Valid`] type-state discharges the obligations that the
+	/// [`Unknown`]-state [`write`](BitPtr::write) documents as `unsafe`, so
+	/// this is safe to call.
+	pub fn write(&self, value: bool) {
+		unsafe { (&*self.addr.to_access()).write_bit::<O>(self.head, value) }
+	}
+}
+
+impl<O, T, M, V> Clone for BitPtr<O, T, M, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+	V: Validity,
 {
 	#[inline(always)]
 	fn clone(&self) -> Self {
@@ -168,19 +523,21 @@ where
 	}
 }
 
-impl<O, T, M> Eq for BitPtr<O, T, M>
+impl<O, T, M, V> Eq for BitPtr<O, T, M, V>
 where
 	O: BitOrder,
 	T: BitStore,
 	M: Mutability,
+	V: Validity,
 {
 }
 
-impl<O, T, M> Ord for BitPtr<O, T, M>
+impl<O, T, M, V> Ord for BitPtr<O, T, M, V>
 where
 	O: BitOrder,
 	T: BitStore,
 	M: Mutability,
+	V: Validity,
 {
 	fn cmp(&self, other: &Self) -> cmp::Ordering {
 		self.partial_cmp(&other)
@@ -188,36 +545,39 @@ where
 	}
 }
 
-impl<O, T, U, M, N> PartialEq<BitPtr<O, U, N>> for BitPtr<O, T, M>
+impl<O, T, U, M, N, V, W> PartialEq<BitPtr<O, U, N, W>> for BitPtr<O, T, M, V>
 where
 	O: BitOrder,
 	T: BitStore,
 	U: BitStore,
 	M: Mutability,
 	N: Mutability,
+	V: Validity,
+	W: Validity,
 {
-	fn eq(&self, other: &BitPtr<O, U, N>) -> bool {
+	fn eq(&self, other: &BitPtr<O, U, N, W>) -> bool {
 		if TypeId::of::<T::Mem>() != TypeId::of::<U::Mem>() {
 			return false;
 		}
-		self.addr.value() == other.addr.value()
-			&& self.head.value() == other.head.value()
+		self.addr() == other.addr() && self.head.value() == other.head.value()
 	}
 }
 
-impl<O, T, U, M, N> PartialOrd<BitPtr<O, U, N>> for BitPtr<O, T, M>
+impl<O, T, U, M, N, V, W> PartialOrd<BitPtr<O, U, N, W>> for BitPtr<O, T, M, V>
 where
 	O: BitOrder,
 	T: BitStore,
 	U: BitStore,
 	M: Mutability,
 	N: Mutability,
+	V: Validity,
+	W: Validity,
 {
-	fn partial_cmp(&self, other: &BitPtr<O, U, N>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitPtr<O, U, N, W>) -> Option<cmp::Ordering> {
 		if TypeId::of::<T::Mem>() != TypeId::of::<U::Mem>() {
 			return None;
 		}
-		match (self.addr.value()).cmp(&(other.addr.value())) {
+		match self.addr().cmp(&other.addr()) {
 			cmp::Ordering::Equal => {
 				self.head.value().partial_cmp(&other.head.value())
 			},
@@ -295,10 +655,309 @@ where
 	}
 }
 
-impl<O, T, M> Copy for BitPtr<O, T, M>
+impl<O, T, M, V> Copy for BitPtr<O, T, M, V>
 where
 	O: BitOrder,
 	T: BitStore,
 	M: Mutability,
+	V: Validity,
 {
-}
\ No newline at end of file
+}
+
+/** A bit pointer stored as a byte offset from a base address, rather than
+an absolute machine address.
+
+# Original
+
+This has no standard-library original; it mirrors how persistent-memory
+pointer types keep an offset relative to a region base, rather than an
+absolute address, so that structures built from one survive being
+re-mapped at a different virtual address — for example, a memory-mapped
+file, or a shared-memory segment, that gets reopened by a different
+process or at a different base.
+
+# API Differences
+
+Unlike [`BitPtr`], `RelBitPtr` carries no provenance at all and cannot be
+read or written directly. [`Self::to_ptr`] rematerializes it into a
+[`BitPtr`] against the base address of whatever region currently holds
+the referent element; that base need not be the address passed to
+[`Self::from_base`], so long as the region's internal layout has not
+changed.
+**/
+pub struct RelBitPtr<O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+{
+	/// Byte offset of the referent element from some region base address.
+	offset: usize,
+	/// Index of the bit within the referent element.
+	head: BitIdx<T::Mem>,
+	/// The ordering used to map `self.head` to an electrical position.
+	_ord: PhantomData<O>,
+	/// The element type and mutability that `self` will rematerialize as.
+	_mem: PhantomData<(T, M)>,
+}
+
+impl<O, T, M> RelBitPtr<O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+{
+	/// Records a live pointer's address as an offset from `base`.
+	///
+	/// # Parameters
+	///
+	/// - `base`: The base address of the pool or mapping that `ptr` lives
+	///   in.
+	/// - `ptr`: A pointer somewhere inside the region based at `base`, in
+	///   any validity state.
+	///
+	/// # Returns
+	///
+	/// A relative pointer that can be serialized alongside the region's
+	/// contents and later rebased onto a new address with [`Self::to_ptr`].
+	pub fn from_base<V>(base: *const u8, ptr: BitPtr<O, T, M, V>) -> Self
+	where V: Validity {
+		let (addr, head) = ptr.raw_parts();
+		Self {
+			offset: addr.addr().wrapping_sub(base.addr()),
+			head,
+			_ord: PhantomData,
+			_mem: PhantomData,
+		}
+	}
+}
+
+impl<O, T> RelBitPtr<O, T, Const>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Rebases this relative pointer onto a region now based at `base`.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `base`: The base address of the region that now holds the
+	///   referent element.
+	///
+	/// # Returns
+	///
+	/// A [`BitPtr`] addressing the same bit within the region, derived from
+	/// `base` with [`<*const u8>::wrapping_add`] rather than a
+	/// `usize as *const T` cast, so it keeps `base`'s provenance and
+	/// remains sound to dereference once its validity is established.
+	/// Returns an error if the rebased address is incorrectly aligned for
+	/// `T`.
+	///
+	/// [`<*const u8>::wrapping_add`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.wrapping_add
+	pub fn to_ptr(
+		self,
+		base: *const u8,
+	) -> Result<BitPtr<O, T, Const, Unknown>, AddressError<T>> {
+		let elem = base.wrapping_add(self.offset) as *const T;
+		BitPtr::new(elem, self.head)
+	}
+}
+
+impl<O, T> RelBitPtr<O, T, Mut>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Rebases this relative pointer onto a region now based at `base`.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `base`: The base address of the region that now holds the
+	///   referent element.
+	///
+	/// # Returns
+	///
+	/// A [`BitPtr`] addressing the same bit within the region, derived from
+	/// `base` with [`<*mut u8>::wrapping_add`] rather than a
+	/// `usize as *mut T` cast, so it keeps `base`'s provenance and remains
+	/// sound to dereference once its validity is established. Returns an
+	/// error if the rebased address is incorrectly aligned for `T`.
+	///
+	/// [`<*mut u8>::wrapping_add`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.wrapping_add
+	pub fn to_ptr(
+		self,
+		base: *mut u8,
+	) -> Result<BitPtr<O, T, Mut, Unknown>, AddressError<T>> {
+		let elem = base.wrapping_add(self.offset) as *mut T;
+		BitPtr::new(elem, self.head)
+	}
+}
+
+impl<O, T, M> Clone for RelBitPtr<O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+{
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<O, T, M> Copy for RelBitPtr<O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: Mutability,
+{
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::order::Lsb0;
+
+	#[test]
+	fn offset_crosses_element_boundary() {
+		let elems = [0u8, 0, 0];
+		let origin = BitPtr::<Lsb0, u8, Const>::from(&elems[0]);
+
+		let moved = unsafe { origin.offset(10) };
+		let expect = BitPtr::<Lsb0, u8, Const>::from(&elems[1]);
+		let (_, head) = moved.raw_parts();
+
+		assert_eq!(moved.addr(), expect.addr());
+		assert_eq!(head.value(), 2);
+	}
+
+	#[test]
+	fn offset_negative_crosses_downward() {
+		let elems = [0u8, 0, 0];
+		let origin = unsafe {
+			BitPtr::<Lsb0, u8, Const>::from(&elems[2]).offset(3)
+		};
+
+		let moved = unsafe { origin.offset(-11) };
+		let expect = BitPtr::<Lsb0, u8, Const>::from(&elems[1]);
+		let (_, head) = moved.raw_parts();
+
+		assert_eq!(moved.addr(), expect.addr());
+		assert_eq!(head.value(), 0);
+	}
+
+	#[test]
+	fn offset_lands_exactly_on_next_element() {
+		let elems = [0u8, 0];
+		let origin = BitPtr::<Lsb0, u8, Const>::from(&elems[0]);
+
+		let moved = unsafe { origin.offset(8) };
+		let expect = BitPtr::<Lsb0, u8, Const>::from(&elems[1]);
+		let (_, head) = moved.raw_parts();
+
+		assert_eq!(moved.addr(), expect.addr());
+		assert_eq!(head.value(), 0);
+	}
+
+	#[test]
+	fn add_then_sub_round_trips() {
+		let elems = [0u8, 0, 0];
+		let origin = BitPtr::<Lsb0, u8, Const>::from(&elems[0]);
+
+		let out = unsafe { origin.add(17) };
+		let back = unsafe { out.sub(17) };
+
+		assert!(back == origin);
+	}
+
+	#[test]
+	fn offset_from_reports_signed_bit_distance() {
+		let elems = [0u8, 0, 0];
+		let low = BitPtr::<Lsb0, u8, Const>::from(&elems[0]);
+		let high = unsafe { low.offset(19) };
+
+		assert_eq!(unsafe { high.offset_from(low) }, 19);
+		assert_eq!(unsafe { low.offset_from(high) }, -19);
+		assert_eq!(unsafe { low.offset_from(low) }, 0);
+	}
+
+	#[test]
+	fn wrapping_offset_matches_offset_in_bounds() {
+		let elems = [0u8, 0, 0];
+		let origin = BitPtr::<Lsb0, u8, Const>::from(&elems[0]);
+
+		let wrapped = origin.wrapping_offset(10);
+		let offset = unsafe { origin.offset(10) };
+
+		assert!(wrapped == offset);
+	}
+
+	#[test]
+	fn try_live_rejects_only_the_dangling_sentinel() {
+		let dangling = BitPtr::<Lsb0, u8, Const>::DANGLING;
+		assert!(dangling.try_live().is_none());
+
+		let elem = 0u8;
+		let live = BitPtr::<Lsb0, u8, Const>::from(&elem);
+		assert!(live.try_live().is_some());
+	}
+
+	#[test]
+	fn validity_transitions_enable_safe_access() {
+		let mut elem = 0u8;
+		let ptr = BitPtr::<Lsb0, u8, Mut>::from(&mut elem);
+
+		unsafe { ptr.write(true) };
+		let valid = unsafe { ptr.assume_live().assume_valid() };
+		assert!(valid.read());
+
+		valid.write(false);
+		assert!(!valid.read());
+	}
+
+	#[test]
+	fn rel_bit_ptr_round_trips_across_rebase() {
+		let pool_a = [0u8; 4];
+		let pool_b = [0u8; 4];
+
+		let original =
+			unsafe { BitPtr::<Lsb0, u8, Const>::from(&pool_a[1]).offset(3) };
+
+		let rel = RelBitPtr::from_base(pool_a.as_ptr(), original);
+		let rebased = rel
+			.to_ptr(pool_b.as_ptr())
+			.expect("aligned address rebases cleanly");
+
+		let expect =
+			unsafe { BitPtr::<Lsb0, u8, Const>::from(&pool_b[1]).offset(3) };
+
+		assert!(rebased == expect);
+	}
+
+	#[test]
+	fn rel_bit_ptr_handles_referent_before_base() {
+		let pool = [0u8; 4];
+		let base = unsafe { pool.as_ptr().add(2) };
+		let ptr = BitPtr::<Lsb0, u8, Const>::from(&pool[0]);
+
+		let rel = RelBitPtr::from_base(base, ptr);
+		let rebased = rel
+			.to_ptr(base)
+			.expect("wrapping arithmetic recovers the original address");
+
+		assert!(rebased == ptr);
+	}
+
+	#[test]
+	fn rel_bit_ptr_to_ptr_reports_misalignment() {
+		let words = [0u32; 2];
+		let base = words.as_ptr() as *const u8;
+		let ptr = BitPtr::<Lsb0, u32, Const>::from(&words[0]);
+		let rel = RelBitPtr::from_base(base, ptr);
+
+		let misaligned_base = unsafe { base.add(1) };
+		assert!(rel.to_ptr(misaligned_base).is_err());
+	}
+}